@@ -1,23 +1,93 @@
-use std::{ fs, collections::HashMap };
+use std::{ fs, collections::{ BTreeMap, HashMap }, path::{ Path, PathBuf } };
 use structopt::StructOpt;
+use serde::Deserialize;
 
-#[derive(StructOpt)]
+#[derive(StructOpt, Deserialize)]
 #[structopt(name = "C++ Project Generator", about = "This tool generates base C++ project.")]
 struct Config {
     #[structopt(short, long, default_value = "project")]
+    #[serde(default = "default_name")]
     name: String,
-    
+
     #[structopt(long, default_value = "exec")]
+    #[serde(default = "default_config_type")]
     config_type: String,
 
     #[structopt(long)]
+    #[serde(default)]
     use_clang_tidy: bool,
 
     #[structopt(long)]
+    #[serde(default)]
     use_conan: bool,
 
     #[structopt(short, long, default_value = "cmake")]
+    #[serde(default = "default_generator")]
     generator: String,
+
+    #[structopt(long, default_value = "both")]
+    #[serde(default = "default_shell")]
+    shell: String,
+
+    #[structopt(long, default_value = "2")]
+    #[serde(default = "default_conan_version")]
+    conan_version: u8,
+
+    #[structopt(long)]
+    #[serde(default)]
+    use_presets: bool,
+
+    #[structopt(long)]
+    #[serde(default)]
+    with_tests: Option<String>,
+
+    #[structopt(long)]
+    #[serde(default)]
+    use_sanitizers: bool,
+
+    #[structopt(long)]
+    #[serde(default)]
+    use_cppcheck: bool,
+
+    #[structopt(long)]
+    #[serde(default)]
+    warnings_as_errors: bool,
+
+    #[structopt(long, parse(from_os_str))]
+    #[serde(skip)]
+    from: Option<PathBuf>,
+
+    #[structopt(skip)]
+    #[serde(default, rename = "dependencies")]
+    dependencies: BTreeMap<String, String>,
+}
+
+fn default_name() -> String { "project".to_string() }
+fn default_config_type() -> String { "exec".to_string() }
+fn default_generator() -> String { "cmake".to_string() }
+fn default_shell() -> String { "both".to_string() }
+fn default_conan_version() -> u8 { 2 }
+
+fn to_pascal_case(name: &str) -> String {
+    let pascal_case: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+
+    if pascal_case.is_empty() {
+        "Project".to_string()
+    } else if pascal_case.chars().next().unwrap().is_ascii_digit() {
+        format!("Project{}", pascal_case)
+    } else {
+        pascal_case
+    }
 }
 
 static MAIN_CPP: &str = "\
@@ -44,81 +114,347 @@ static GITIGNORE: &str = "\
 
 trait Generator {
     fn generate_build_file(&self, config: &Config);
-    fn setup_cmd(&self) -> &'static str;
-    fn build_cmd(&self) -> &'static str;
+    fn setup_cmd(&self, config: &Config) -> &'static str;
+    fn build_cmd(&self, config: &Config) -> &'static str;
 }
 
 struct CMakeGen;
 
 impl Generator for CMakeGen {
     fn generate_build_file(&self, config: &Config) {
+        let use_static_analyzers = config.use_clang_tidy || config.use_cppcheck;
+
         let mut cmake_file = String::new();
         cmake_file.push_str("cmake_minimum_required(VERSION 3.20)\n");
         cmake_file.push_str(format!("project({} VERSION 0.1.0)\n", config.name).as_str());
 
-        if config.use_clang_tidy {
-            cmake_file.push_str("set(CMAKE_CXX_CLANG_TIDY \"clang-tidy;-format-style=file;--use-color;-header-filter=.*\")\n");
+        cmake_file.push_str("\nlist(APPEND CMAKE_MODULE_PATH \"${CMAKE_SOURCE_DIR}/cmake\")\n\n");
+        cmake_file.push_str("include(PreventInSourceBuilds)\n");
+        cmake_file.push_str("include(CompilerWarnings)\n");
+
+        if use_static_analyzers {
+            cmake_file.push_str("include(StaticAnalyzers)\n");
         }
 
+        if config.use_sanitizers {
+            cmake_file.push_str("include(Sanitizers)\n");
+        }
+
+        cmake_file.push('\n');
         cmake_file.push_str(format!("set({}_SRC_DIR \"src\")\n", config.name.to_uppercase()).as_str());
         cmake_file.push_str(format!("set({}_INCLUDE_DIR \"include\")\n", config.name.to_uppercase()).as_str());
         cmake_file.push_str(format!("include_directories({} PUBLIC {}_INCLUDE_DIR)\n", config.name, config.name.to_uppercase()).as_str());
         
-        if config.use_conan {
+        if config.use_conan && config.conan_version == 1 {
             cmake_file.push_str("include(${CMAKE_BINARY_DIR}/conanbuildinfo.cmake)\n");
+        } else if config.use_conan && !config.use_presets {
+            cmake_file.push_str("include(${CMAKE_BINARY_DIR}/generators/conan_toolchain.cmake)\n");
         }
 
         if config.config_type == "exec" {
             cmake_file.push_str(format!("add_executable({} src/main.cpp)\n", config.name).as_str());
-        } 
+        }
         else if config.config_type == "lib" {
             cmake_file.push_str(format!("add_library({0} src/{0}.cpp)\n", config.name).as_str());
         }
 
-        if config.use_conan {
+        if config.use_conan && config.conan_version == 1 {
             cmake_file.push_str(format!("target_link_libraries({} ${{CONAN_LIBS}})\n", config.name).as_str());
         }
 
-        cmake_file.push_str("set(ERROR_LIST \"-Werror=return-type -Werror=unused-result\")\n");
-        cmake_file.push_str("set(CMAKE_CXX_FLAGS \"${CMAKE_CXX_FLAGS} -std=c++20 -Wall -Wextra ${ERROR_LIST}\")\n");
-        
-        fs::write(&(config.name.clone() + "\\CMakeLists.txt"), cmake_file).expect("Failed to create CMake file");
+        cmake_file.push_str(format!("target_compile_features({} PUBLIC cxx_std_20)\n", config.name).as_str());
+        cmake_file.push_str(format!("target_link_libraries({} PRIVATE project_warnings)\n", config.name).as_str());
+
+        if config.use_sanitizers {
+            cmake_file.push_str(format!("target_link_libraries({} PRIVATE project_sanitizers)\n", config.name).as_str());
+        }
+
+        if let Some(framework) = &config.with_tests {
+            cmake_file.push_str("\nenable_testing()\n");
+            cmake_file.push_str("include(CTest)\n");
+            cmake_file.push_str("include(FetchContent)\n");
+
+            if framework == "catch2" {
+                cmake_file.push_str("FetchContent_Declare(\n");
+                cmake_file.push_str("    Catch2\n");
+                cmake_file.push_str("    GIT_REPOSITORY https://github.com/catchorg/Catch2.git\n");
+                cmake_file.push_str("    GIT_TAG v3.5.2\n");
+                cmake_file.push_str(")\n");
+                cmake_file.push_str("FetchContent_MakeAvailable(Catch2)\n");
+                cmake_file.push_str("list(APPEND CMAKE_MODULE_PATH ${catch2_SOURCE_DIR}/extras)\n");
+            } else if framework == "gtest" {
+                cmake_file.push_str("FetchContent_Declare(\n");
+                cmake_file.push_str("    googletest\n");
+                cmake_file.push_str("    GIT_REPOSITORY https://github.com/google/googletest.git\n");
+                cmake_file.push_str("    GIT_TAG v1.14.0\n");
+                cmake_file.push_str(")\n");
+                cmake_file.push_str("FetchContent_MakeAvailable(googletest)\n");
+            }
+
+            cmake_file.push_str("add_subdirectory(test)\n");
+        }
+
+        fs::write(Path::new(&config.name).join("CMakeLists.txt"), cmake_file).expect("Failed to create CMake file");
+
+        Self::generate_cmake_modules(config, use_static_analyzers);
+
+        if config.use_presets {
+            Self::generate_presets_file(config);
+        }
+
+        if let Some(framework) = &config.with_tests {
+            Self::generate_test_files(config, framework);
+        }
+    }
+
+    fn setup_cmd(&self, config: &Config) -> &'static str {
+        if config.use_presets {
+            "cmake --preset debug"
+        } else {
+            "cmake -G Ninja -S . -B build -DCMAKE_EXPORT_COMPILE_COMMANDS=1"
+        }
+    }
+
+    fn build_cmd(&self, config: &Config) -> &'static str {
+        if config.use_presets {
+            "cmake --build --preset debug"
+        } else {
+            "cmake --build ./build"
+        }
     }
+}
+
+impl CMakeGen {
+    fn generate_cmake_modules(config: &Config, use_static_analyzers: bool) {
+        let cmake_dir = Path::new(&config.name).join("cmake");
+        fs::create_dir(&cmake_dir).expect("Failed to create \"cmake\" directory");
+
+        let mut prevent_in_source_builds = String::new();
+        prevent_in_source_builds.push_str("if(CMAKE_SOURCE_DIR STREQUAL CMAKE_BINARY_DIR)\n");
+        prevent_in_source_builds.push_str("    message(FATAL_ERROR \"In-source builds are not allowed. Please create a separate build directory, e.g. `cmake -S . -B build`.\")\n");
+        prevent_in_source_builds.push_str("endif()\n");
+        fs::write(cmake_dir.join("PreventInSourceBuilds.cmake"), prevent_in_source_builds).expect("Failed to create PreventInSourceBuilds.cmake file");
+
+        let mut compiler_warnings = String::new();
+        compiler_warnings.push_str("add_library(project_warnings INTERFACE)\n\n");
+        compiler_warnings.push_str(format!("option(WARNINGS_AS_ERRORS \"Treat compiler warnings as errors\" {})\n\n", if config.warnings_as_errors { "ON" } else { "OFF" }).as_str());
+        compiler_warnings.push_str("set(PROJECT_WARNINGS\n");
+        compiler_warnings.push_str("    -Wall\n");
+        compiler_warnings.push_str("    -Wextra\n");
+        compiler_warnings.push_str("    -Wshadow\n");
+        compiler_warnings.push_str("    -Wconversion\n");
+        compiler_warnings.push_str("    -Wpedantic\n");
+        compiler_warnings.push_str("    -Wnon-virtual-dtor\n");
+        compiler_warnings.push_str("    -Wold-style-cast\n");
+        compiler_warnings.push_str("    -Wcast-align\n");
+        compiler_warnings.push_str("    -Wunused\n");
+        compiler_warnings.push_str("    -Woverloaded-virtual\n");
+        compiler_warnings.push_str("    -Wnull-dereference\n");
+        compiler_warnings.push_str("    -Wdouble-promotion\n");
+        compiler_warnings.push_str(")\n\n");
+        compiler_warnings.push_str("if(WARNINGS_AS_ERRORS)\n");
+        compiler_warnings.push_str("    list(APPEND PROJECT_WARNINGS -Werror)\n");
+        compiler_warnings.push_str("endif()\n\n");
+        compiler_warnings.push_str("target_compile_options(project_warnings INTERFACE ${PROJECT_WARNINGS})\n");
+        fs::write(cmake_dir.join("CompilerWarnings.cmake"), compiler_warnings).expect("Failed to create CompilerWarnings.cmake file");
+
+        if use_static_analyzers {
+            let mut static_analyzers = String::new();
+            static_analyzers.push_str(format!("option(ENABLE_CLANG_TIDY \"Enable clang-tidy static analysis\" {})\n", if config.use_clang_tidy { "ON" } else { "OFF" }).as_str());
+            static_analyzers.push_str(format!("option(ENABLE_CPPCHECK \"Enable cppcheck static analysis\" {})\n\n", if config.use_cppcheck { "ON" } else { "OFF" }).as_str());
+
+            static_analyzers.push_str("if(ENABLE_CLANG_TIDY)\n");
+            static_analyzers.push_str("    find_program(CLANGTIDY clang-tidy)\n");
+            static_analyzers.push_str("    if(CLANGTIDY)\n");
+            static_analyzers.push_str("        set(CMAKE_CXX_CLANG_TIDY ${CLANGTIDY} -format-style=file --use-color -header-filter=.*)\n");
+            static_analyzers.push_str("    else()\n");
+            static_analyzers.push_str("        message(SEND_ERROR \"ENABLE_CLANG_TIDY is ON but clang-tidy was not found\")\n");
+            static_analyzers.push_str("    endif()\n");
+            static_analyzers.push_str("endif()\n\n");
+
+            static_analyzers.push_str("if(ENABLE_CPPCHECK)\n");
+            static_analyzers.push_str("    find_program(CPPCHECK cppcheck)\n");
+            static_analyzers.push_str("    if(CPPCHECK)\n");
+            static_analyzers.push_str("        set(CMAKE_CXX_CPPCHECK ${CPPCHECK} --enable=all --inconclusive --inline-suppr)\n");
+            static_analyzers.push_str("    else()\n");
+            static_analyzers.push_str("        message(SEND_ERROR \"ENABLE_CPPCHECK is ON but cppcheck was not found\")\n");
+            static_analyzers.push_str("    endif()\n");
+            static_analyzers.push_str("endif()\n");
+            fs::write(cmake_dir.join("StaticAnalyzers.cmake"), static_analyzers).expect("Failed to create StaticAnalyzers.cmake file");
+        }
 
-    fn setup_cmd(&self) -> &'static str {
-        "cmake -G Ninja -S . -B build -DCMAKE_EXPORT_COMPILE_COMMANDS=1"
+        if config.use_sanitizers {
+            let mut sanitizers = String::new();
+            sanitizers.push_str("add_library(project_sanitizers INTERFACE)\n\n");
+            sanitizers.push_str("option(ENABLE_SANITIZER_ADDRESS \"Enable address sanitizer\" ON)\n");
+            sanitizers.push_str("option(ENABLE_SANITIZER_UNDEFINED \"Enable undefined behavior sanitizer\" ON)\n");
+            sanitizers.push_str("option(ENABLE_SANITIZER_THREAD \"Enable thread sanitizer\" OFF)\n\n");
+            sanitizers.push_str("set(SANITIZERS \"\")\n\n");
+            sanitizers.push_str("if(ENABLE_SANITIZER_ADDRESS)\n");
+            sanitizers.push_str("    list(APPEND SANITIZERS \"address\")\n");
+            sanitizers.push_str("endif()\n\n");
+            sanitizers.push_str("if(ENABLE_SANITIZER_UNDEFINED)\n");
+            sanitizers.push_str("    list(APPEND SANITIZERS \"undefined\")\n");
+            sanitizers.push_str("endif()\n\n");
+            sanitizers.push_str("if(ENABLE_SANITIZER_THREAD)\n");
+            sanitizers.push_str("    list(APPEND SANITIZERS \"thread\")\n");
+            sanitizers.push_str("endif()\n\n");
+            sanitizers.push_str("list(JOIN SANITIZERS \",\" SANITIZERS_JOINED)\n\n");
+            sanitizers.push_str("if(SANITIZERS_JOINED)\n");
+            sanitizers.push_str("    target_compile_options(project_sanitizers INTERFACE -fsanitize=${SANITIZERS_JOINED})\n");
+            sanitizers.push_str("    target_link_options(project_sanitizers INTERFACE -fsanitize=${SANITIZERS_JOINED})\n");
+            sanitizers.push_str("endif()\n");
+            fs::write(cmake_dir.join("Sanitizers.cmake"), sanitizers).expect("Failed to create Sanitizers.cmake file");
+        }
     }
 
-    fn build_cmd(&self) -> &'static str {
-        "cmake --build ./build"
+    fn generate_presets_file(config: &Config) {
+        let mut presets_file = String::new();
+        presets_file.push_str("{\n");
+        presets_file.push_str("    \"version\": 3,\n");
+        presets_file.push_str("    \"configurePresets\": [\n");
+        presets_file.push_str("        {\n");
+        presets_file.push_str("            \"name\": \"base\",\n");
+        presets_file.push_str("            \"hidden\": true,\n");
+        presets_file.push_str("            \"binaryDir\": \"${sourceDir}/build\",\n");
+        presets_file.push_str("            \"generator\": \"Ninja\",\n");
+        presets_file.push_str("            \"cacheVariables\": {\n");
+        presets_file.push_str("                \"CMAKE_EXPORT_COMPILE_COMMANDS\": \"ON\"\n");
+        presets_file.push_str("            }");
+
+        if config.use_conan && config.conan_version == 2 {
+            presets_file.push_str(",\n            \"toolchainFile\": \"${sourceDir}/build/generators/conan_toolchain.cmake\"\n");
+        } else {
+            presets_file.push('\n');
+        }
+
+        presets_file.push_str("        },\n");
+        presets_file.push_str("        {\n");
+        presets_file.push_str("            \"name\": \"debug\",\n");
+        presets_file.push_str("            \"inherits\": \"base\",\n");
+        presets_file.push_str("            \"cacheVariables\": {\n");
+        presets_file.push_str("                \"CMAKE_BUILD_TYPE\": \"Debug\"\n");
+        presets_file.push_str("            }\n");
+        presets_file.push_str("        },\n");
+        presets_file.push_str("        {\n");
+        presets_file.push_str("            \"name\": \"release\",\n");
+        presets_file.push_str("            \"inherits\": \"base\",\n");
+        presets_file.push_str("            \"cacheVariables\": {\n");
+        presets_file.push_str("                \"CMAKE_BUILD_TYPE\": \"Release\"\n");
+        presets_file.push_str("            }\n");
+        presets_file.push_str("        }\n");
+        presets_file.push_str("    ],\n");
+        presets_file.push_str("    \"buildPresets\": [\n");
+        presets_file.push_str("        {\n");
+        presets_file.push_str("            \"name\": \"debug\",\n");
+        presets_file.push_str("            \"configurePreset\": \"debug\"\n");
+        presets_file.push_str("        },\n");
+        presets_file.push_str("        {\n");
+        presets_file.push_str("            \"name\": \"release\",\n");
+        presets_file.push_str("            \"configurePreset\": \"release\"\n");
+        presets_file.push_str("        }\n");
+        presets_file.push_str("    ]\n");
+        presets_file.push_str("}\n");
+
+        fs::write(Path::new(&config.name).join("CMakePresets.json"), presets_file).expect("Failed to create CMakePresets.json file");
+    }
+
+    fn generate_test_files(config: &Config, framework: &str) {
+        let test_dir = Path::new(&config.name).join("test");
+        fs::create_dir(&test_dir).expect("Failed to create \"test\" directory");
+
+        let test_target = format!("{}_tests", config.name);
+
+        let mut test_cmake = String::new();
+        test_cmake.push_str(format!("add_executable({} test_main.cpp)\n", test_target).as_str());
+
+        if framework == "catch2" {
+            test_cmake.push_str(format!("target_link_libraries({} PRIVATE Catch2::Catch2WithMain)\n\n", test_target).as_str());
+            test_cmake.push_str("include(Catch)\n");
+            test_cmake.push_str(format!("catch_discover_tests({})\n", test_target).as_str());
+
+            let test_main = "\
+#define CATCH_CONFIG_MAIN
+#include <catch2/catch_test_macros.hpp>
+
+TEST_CASE(\"sanity check\", \"[sanity]\") {
+    REQUIRE(1 + 1 == 2);
+}";
+            fs::write(test_dir.join("test_main.cpp"), test_main).expect("Failed to create test_main.cpp file");
+        } else if framework == "gtest" {
+            test_cmake.push_str(format!("target_link_libraries({} PRIVATE gtest_main)\n\n", test_target).as_str());
+            test_cmake.push_str(format!("add_test(NAME {0} COMMAND {0})\n", test_target).as_str());
+
+            let test_main = "\
+#include <gtest/gtest.h>
+
+TEST(SanityCheck, AdditionWorks) {
+    EXPECT_EQ(1 + 1, 2);
+}";
+            fs::write(test_dir.join("test_main.cpp"), test_main).expect("Failed to create test_main.cpp file");
+        }
+
+        fs::write(test_dir.join("CMakeLists.txt"), test_cmake).expect("Failed to create test/CMakeLists.txt file");
     }
 }
 
 struct PremakeGen;
 
 impl Generator for PremakeGen {
-    fn generate_build_file(&self, _config: &Config) {
-        
+    fn generate_build_file(&self, config: &Config) {
+        let kind = if config.config_type == "lib" { "StaticLib" } else { "ConsoleApp" };
+
+        let mut premake_file = String::new();
+        premake_file.push_str(format!("workspace \"{}\"\n", config.name).as_str());
+        premake_file.push_str("    configurations { \"Debug\", \"Release\" }\n\n");
+
+        premake_file.push_str(format!("project \"{}\"\n", config.name).as_str());
+        premake_file.push_str(format!("    kind \"{}\"\n", kind).as_str());
+        premake_file.push_str("    language \"C++\"\n");
+        premake_file.push_str("    cppdialect \"C++20\"\n");
+        premake_file.push_str("    files { \"src/**.cpp\", \"include/**.h\" }\n");
+        premake_file.push_str("    includedirs { \"include\" }\n\n");
+
+        premake_file.push_str("    filter \"configurations:Debug\"\n");
+        premake_file.push_str("        defines { \"DEBUG\" }\n");
+        premake_file.push_str("        symbols \"On\"\n\n");
+
+        premake_file.push_str("    filter \"configurations:Release\"\n");
+        premake_file.push_str("        defines { \"NDEBUG\" }\n");
+        premake_file.push_str("        optimize \"On\"\n");
+
+        fs::write(Path::new(&config.name).join("premake5.lua"), premake_file).expect("Failed to create premake5.lua file");
     }
 
-    fn setup_cmd(&self) -> &'static str {
-        "premake5 vs2022"
+    fn setup_cmd(&self, config: &Config) -> &'static str {
+        if config.shell == "bat" {
+            "premake5 vs2022"
+        } else {
+            "premake5 gmake2"
+        }
     }
 
-    fn build_cmd(&self) -> &'static str {
+    fn build_cmd(&self, _config: &Config) -> &'static str {
         ""
     }
 }
 
 impl Config {
     fn create_project(&self) {
-        println!("Creating C++ project...");
-        self.create_default_dirs();
+        if self.shell != "bat" && self.shell != "sh" && self.shell != "both" {
+            panic!("Specified unsupported shell");
+        }
+
+        if let Some(framework) = &self.with_tests {
+            if framework != "catch2" && framework != "gtest" {
+                panic!("Specified unsupported test framework");
+            }
+        }
 
         let supported_generators: HashMap<String, Box<dyn Generator>> = HashMap::from(
             [
                 ("cmake".to_string(), Box::new(CMakeGen{}) as Box<dyn Generator>),
-                //("premake", Premake(PremakeGen{})),
+                ("premake".to_string(), Box::new(PremakeGen{}) as Box<dyn Generator>),
             ]
         );
 
@@ -129,49 +465,160 @@ impl Config {
             None => { panic!("Specified unsupported generator") }
         };
 
+        println!("Creating C++ project...");
+        self.create_default_dirs();
+
         gen.generate_build_file(self);
-        self.create_cmd_shell_files(gen.build_cmd().to_string(), gen.setup_cmd().to_string());
+        self.create_cmd_shell_files(gen.build_cmd(self).to_string(), gen.setup_cmd(self).to_string());
+
+        let root = Path::new(&self.name);
+
+        if self.use_conan && self.conan_version == 1 {
+            let mut requires = String::new();
+            for (package, version) in &self.dependencies {
+                requires.push_str(format!("{}/{}\n", package, version).as_str());
+            }
 
-        if self.use_conan {
-            let conan_file = format!("[requires]\n\n[generators]\n{}", self.generator);
-            fs::write(&(self.name.clone() + "\\conanfile.txt"), conan_file).expect("Failed to create conanfile.txt file");
+            let conan_file = format!("[requires]\n{}\n[generators]\n{}", requires, self.generator);
+            fs::write(root.join("conanfile.txt"), conan_file).expect("Failed to create conanfile.txt file");
+        } else if self.use_conan {
+            fs::write(root.join("conanfile.py"), self.make_conanfile_py()).expect("Failed to create conanfile.py file");
         }
 
         if self.use_clang_tidy {
-            fs::write(&(self.name.clone() + "\\.clang-tidy"), CLANG_TIDY).expect("Failed to create .clang-tidy file");
+            fs::write(root.join(".clang-tidy"), CLANG_TIDY).expect("Failed to create .clang-tidy file");
         }
 
-        fs::write(&(self.name.clone() + "\\.gitignore"), GITIGNORE).expect("Failed to create .gitignore file");
+        fs::write(root.join(".gitignore"), GITIGNORE).expect("Failed to create .gitignore file");
 
         println!("Done.");
     }
 
     fn create_default_dirs(&self) {
+        let root = PathBuf::from(&self.name);
         let dirs = vec![
-            self.name.clone(),
-            self.name.clone() + "\\src",
-            self.name.clone() + "\\include",
-            self.name.clone() + "\\build",
+            root.clone(),
+            root.join("src"),
+            root.join("include"),
+            root.join("build"),
         ];
 
         for dir in &dirs {
-            fs::create_dir(dir).expect(format!("Failed to create \"{}\" directory", dir).as_str());
+            fs::create_dir(dir).unwrap_or_else(|_| panic!("Failed to create \"{}\" directory", dir.display()));
         }
 
-        fs::write(dirs[1].clone() + "\\main.cpp", MAIN_CPP).expect("Failed to create main.cpp file");
+        fs::write(dirs[1].join("main.cpp"), MAIN_CPP).expect("Failed to create main.cpp file");
     }
 
-    fn create_cmd_shell_files(&self, build: String, mut setup: String) {
-        if self.use_conan {
-            setup = format!("cd build\nconan install .. --build missing\ncd ..\n{}", setup);
+    fn create_cmd_shell_files(&self, build: String, setup: String) {
+        let root = Path::new(&self.name);
+
+        let mut bat_setup = setup.clone();
+        let mut sh_setup = setup;
+
+        if self.use_conan && self.conan_version == 1 {
+            bat_setup = format!("cd build\nconan install .. --build missing\ncd ..\n{}", bat_setup);
+            sh_setup = format!("cd build\nconan install .. --build missing\ncd ..\n{}", sh_setup);
+        } else if self.use_conan {
+            bat_setup = format!("conan install . --output-folder=build --build=missing\n{}", bat_setup);
+            sh_setup = format!("conan install . --output-folder=build --build=missing\n{}", sh_setup);
         }
 
-        fs::write(&(self.name.clone() + "\\setup.bat"), setup).expect("Failed to create setup.bat file");
-        fs::write(&(self.name.clone() + "\\build.bat"), build).expect("Failed to create build.bat file");
+        if self.shell == "bat" || self.shell == "both" {
+            fs::write(root.join("setup.bat"), &bat_setup).expect("Failed to create setup.bat file");
+            fs::write(root.join("build.bat"), &build).expect("Failed to create build.bat file");
+        }
+
+        if self.shell == "sh" || self.shell == "both" {
+            let setup_sh = format!("#!/bin/sh\n{}\n", sh_setup);
+            let build_sh = format!("#!/bin/sh\n{}\n", build);
+
+            let setup_path = root.join("setup.sh");
+            let build_path = root.join("build.sh");
+
+            fs::write(&setup_path, setup_sh).expect("Failed to create setup.sh file");
+            fs::write(&build_path, build_sh).expect("Failed to create build.sh file");
+
+            Self::make_executable(&setup_path);
+            Self::make_executable(&build_path);
+        }
+    }
+
+    fn make_conanfile_py(&self) -> String {
+        let class_name = format!("{}Recipe", to_pascal_case(&self.name));
+        let is_cmake = self.generator == "cmake";
+
+        let mut conanfile = String::new();
+        conanfile.push_str("from conan import ConanFile\n");
+
+        if is_cmake {
+            conanfile.push_str("from conan.tools.cmake import CMakeToolchain, CMakeDeps, CMake, cmake_layout\n\n");
+        } else {
+            conanfile.push_str("from conan.tools.layout import basic_layout\n\n");
+        }
+
+        conanfile.push_str(format!("class {}(ConanFile):\n", class_name).as_str());
+        conanfile.push_str("    settings = \"os\", \"compiler\", \"build_type\", \"arch\"\n");
+
+        if is_cmake {
+            conanfile.push_str("    generators = \"CMakeToolchain\", \"CMakeDeps\"\n\n");
+        } else {
+            conanfile.push('\n');
+        }
+
+        conanfile.push_str("    def layout(self):\n");
+        if is_cmake {
+            conanfile.push_str("        cmake_layout(self)\n\n");
+        } else {
+            conanfile.push_str("        basic_layout(self)\n\n");
+        }
+
+        if !self.dependencies.is_empty() {
+            conanfile.push_str("    def requirements(self):\n");
+            for (package, version) in &self.dependencies {
+                conanfile.push_str(format!("        self.requires(\"{}/{}\")\n", package, version).as_str());
+            }
+            conanfile.push('\n');
+        }
+
+        if is_cmake {
+            conanfile.push_str("    def generate(self):\n");
+            conanfile.push_str("        tc = CMakeToolchain(self)\n");
+            conanfile.push_str("        tc.generate()\n");
+            conanfile.push_str("        deps = CMakeDeps(self)\n");
+            conanfile.push_str("        deps.generate()\n\n");
+            conanfile.push_str("    def build(self):\n");
+            conanfile.push_str("        cmake = CMake(self)\n");
+            conanfile.push_str("        cmake.configure()\n");
+            conanfile.push_str("        cmake.build()\n");
+        }
+
+        conanfile
     }
+
+    #[cfg(unix)]
+    fn make_executable(path: &Path) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut perms = fs::metadata(path).expect("Failed to read file metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(path, perms).expect("Failed to set executable permissions");
+    }
+
+    #[cfg(not(unix))]
+    fn make_executable(_path: &Path) {}
 }
 
 fn main() {
     let config = Config::from_args();
+
+    let config = match &config.from {
+        Some(path) => {
+            let manifest = fs::read_to_string(path).expect("Failed to read manifest file");
+            toml::from_str(&manifest).expect("Failed to parse manifest file")
+        },
+        None => config,
+    };
+
     config.create_project();
 }